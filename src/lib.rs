@@ -49,11 +49,13 @@ use clipper_sys::{
     ClipType_ctUnion, ClipType_ctXor, EndType as ClipperEndType, EndType_etClosedLine,
     EndType_etClosedPolygon, EndType_etOpenButt, EndType_etOpenRound, EndType_etOpenSquare,
     JoinType as ClipperJoinType, JoinType_jtMiter, JoinType_jtRound, JoinType_jtSquare, Path,
-    PolyFillType_pftNonZero, PolyType, PolyType_ptClip, PolyType_ptSubject,
-    Polygon as ClipperPolygon, Polygons, Vertice,
+    PolyFillType as ClipperPolyFillType, PolyFillType_pftEvenOdd, PolyFillType_pftNegative,
+    PolyFillType_pftNonZero, PolyFillType_pftPositive, PolyType, PolyType_ptClip,
+    PolyType_ptSubject, Polygon as ClipperPolygon, Polygons, Vertice,
 };
-use geo_types::{Coordinate, LineString, MultiLineString, MultiPolygon, Polygon};
+use geo_types::{Coordinate, CoordFloat, LineString, MultiLineString, MultiPolygon, Polygon};
 use std::convert::TryInto;
+use std::marker::PhantomData;
 
 #[derive(Clone, Copy)]
 pub enum JoinType {
@@ -71,6 +73,55 @@ pub enum EndType {
     OpenRound(f64),
 }
 
+/// The boolean operation [`ClipperBuilder::execute`] runs across its accumulated
+/// subject and clip paths.
+#[derive(Clone, Copy)]
+pub enum BooleanOp {
+    Difference,
+    Intersection,
+    Union,
+    Xor,
+}
+
+impl From<BooleanOp> for ClipType {
+    fn from(op: BooleanOp) -> Self {
+        match op {
+            BooleanOp::Difference => ClipType_ctDifference,
+            BooleanOp::Intersection => ClipType_ctIntersection,
+            BooleanOp::Union => ClipType_ctUnion,
+            BooleanOp::Xor => ClipType_ctXor,
+        }
+    }
+}
+
+/// The fill rule used to determine which regions of a self-intersecting or
+/// overlapping set of rings are considered "inside" during a boolean operation.
+#[derive(Clone, Copy)]
+pub enum FillRule {
+    /// Alternates inside/outside at every edge crossing, regardless of winding direction.
+    EvenOdd,
+    /// Keeps regions whose winding count is non-zero. This is the default used by
+    /// the convenience methods on [`Clipper`] and [`ClipperOpen`].
+    NonZero,
+    /// Keeps regions whose winding count is strictly positive, letting ring
+    /// orientation distinguish solids from holes.
+    Positive,
+    /// Keeps regions whose winding count is strictly negative, letting ring
+    /// orientation distinguish solids from holes.
+    Negative,
+}
+
+impl From<FillRule> for ClipperPolyFillType {
+    fn from(fr: FillRule) -> Self {
+        match fr {
+            FillRule::EvenOdd => PolyFillType_pftEvenOdd,
+            FillRule::NonZero => PolyFillType_pftNonZero,
+            FillRule::Positive => PolyFillType_pftPositive,
+            FillRule::Negative => PolyFillType_pftNegative,
+        }
+    }
+}
+
 impl From<JoinType> for ClipperJoinType {
     fn from(jt: JoinType) -> Self {
         match jt {
@@ -93,18 +144,20 @@ impl From<EndType> for ClipperEndType {
     }
 }
 
-struct ClipperPolygons {
+struct ClipperPolygons<T: CoordFloat> {
     pub polygons: Polygons,
     pub factor: f64,
+    _marker: PhantomData<T>,
 }
 
-struct ClipperPath {
+struct ClipperPath<T: CoordFloat> {
     pub path: Path,
     pub factor: f64,
+    _marker: PhantomData<T>,
 }
 
-impl From<ClipperPolygons> for MultiPolygon<f64> {
-    fn from(polygons: ClipperPolygons) -> Self {
+impl<T: CoordFloat> From<ClipperPolygons<T>> for MultiPolygon<T> {
+    fn from(polygons: ClipperPolygons<T>) -> Self {
         polygons
             .polygons
             .polygons()
@@ -115,6 +168,7 @@ impl From<ClipperPolygons> for MultiPolygon<f64> {
                     ClipperPath {
                         path: *paths.first()?,
                         factor: polygons.factor,
+                        _marker: PhantomData,
                     }
                     .into(),
                     paths
@@ -124,6 +178,7 @@ impl From<ClipperPolygons> for MultiPolygon<f64> {
                             ClipperPath {
                                 path: *path,
                                 factor: polygons.factor,
+                                _marker: PhantomData,
                             }
                             .into()
                         })
@@ -134,8 +189,8 @@ impl From<ClipperPolygons> for MultiPolygon<f64> {
     }
 }
 
-impl From<ClipperPolygons> for MultiLineString<f64> {
-    fn from(polygons: ClipperPolygons) -> Self {
+impl<T: CoordFloat> From<ClipperPolygons<T>> for MultiLineString<T> {
+    fn from(polygons: ClipperPolygons<T>) -> Self {
         MultiLineString(
             polygons
                 .polygons
@@ -146,6 +201,7 @@ impl From<ClipperPolygons> for MultiLineString<f64> {
                         ClipperPath {
                             path: *path,
                             factor: polygons.factor,
+                            _marker: PhantomData,
                         }
                         .into()
                     })
@@ -155,14 +211,116 @@ impl From<ClipperPolygons> for MultiLineString<f64> {
     }
 }
 
-impl From<ClipperPath> for LineString<f64> {
-    fn from(path: ClipperPath) -> Self {
+/// Computes twice the signed area of a path in the scaled integer space via the
+/// shoelace formula. The sign reflects winding direction and is used to tell
+/// outer contours apart from holes, mirroring Clipper's own PolyTree classification.
+/// The products are computed in `f64` since scaled coordinates routinely approach
+/// `CLIPPER_MAX_COORDINATE`, where `i64` multiplication would overflow.
+fn path_signed_area(path: &Path) -> f64 {
+    let vertices = path.vertices();
+    let mut area = 0.0;
+    for i in 0..vertices.len() {
+        let [x1, y1] = vertices[i];
+        let [x2, y2] = vertices[(i + 1) % vertices.len()];
+        area += x1 as f64 * y2 as f64 - x2 as f64 * y1 as f64;
+    }
+    area
+}
+
+/// Ray-casting point-in-polygon test against an integer path. The crossing-point
+/// cross product and division are computed in `f64` for the same overflow and
+/// truncation reasons as [`path_signed_area`].
+fn path_contains_point(path: &Path, point: Vertice) -> bool {
+    let vertices = path.vertices();
+    let mut inside = false;
+    let mut j = vertices.len() - 1;
+    for i in 0..vertices.len() {
+        let [xi, yi] = vertices[i];
+        let [xj, yj] = vertices[j];
+        let (xi, yi, xj, yj) = (xi as f64, yi as f64, xj as f64, yj as f64);
+        let (px, py) = (point[0] as f64, point[1] as f64);
+        if ((yi > py) != (yj > py)) && (px < (xj - xi) * (py - yi) / (yj - yi) + xi) {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Rebuilds the outer/hole hierarchy of a Clipper solution the way Clipper's own
+/// PolyTree-to-ExPolygon conversion does, instead of assuming every polygon's
+/// first path is the sole outer and the rest are its holes. Every returned path
+/// is classified as an outer or a hole by the sign of its signed area, then each
+/// hole is assigned to the smallest-area outer that contains one of its vertices.
+fn nest_polygons<T: CoordFloat>(polygons: ClipperPolygons<T>) -> MultiPolygon<T> {
+    let all_paths: Vec<Path> = polygons
+        .polygons
+        .polygons()
+        .iter()
+        .flat_map(|polygon| polygon.paths().iter().copied())
+        .collect();
+
+    let mut outers: Vec<(Path, f64, Vec<Path>)> = Vec::new();
+    let mut holes: Vec<Path> = Vec::new();
+
+    for path in all_paths {
+        if path_signed_area(&path) >= 0.0 {
+            outers.push((path, path_signed_area(&path).abs(), Vec::new()));
+        } else {
+            holes.push(path);
+        }
+    }
+
+    for hole in holes {
+        let hole_vertex = match hole.vertices().first() {
+            Some(vertex) => *vertex,
+            None => continue,
+        };
+
+        let owner = outers
+            .iter_mut()
+            .filter(|(outer, _, _)| path_contains_point(outer, hole_vertex))
+            .min_by(|(_, a, _), (_, b, _)| a.partial_cmp(b).unwrap());
+
+        if let Some((_, _, owner_holes)) = owner {
+            owner_holes.push(hole);
+        }
+    }
+
+    outers
+        .into_iter()
+        .map(|(outer, _, holes)| {
+            Polygon::new(
+                ClipperPath {
+                    path: outer,
+                    factor: polygons.factor,
+                    _marker: PhantomData,
+                }
+                .into(),
+                holes
+                    .into_iter()
+                    .map(|hole| {
+                        ClipperPath {
+                            path: hole,
+                            factor: polygons.factor,
+                            _marker: PhantomData,
+                        }
+                        .into()
+                    })
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+impl<T: CoordFloat> From<ClipperPath<T>> for LineString<T> {
+    fn from(path: ClipperPath<T>) -> Self {
         path.path
             .vertices()
             .iter()
             .map(|vertice| Coordinate {
-                x: vertice[0] as f64 / path.factor,
-                y: vertice[1] as f64 / path.factor,
+                x: T::from(vertice[0] as f64 / path.factor).unwrap(),
+                y: T::from(vertice[1] as f64 / path.factor).unwrap(),
             })
             .collect()
     }
@@ -171,10 +329,71 @@ impl From<ClipperPath> for LineString<f64> {
 pub trait OpenPath {}
 pub trait ClosedPoly {}
 
-impl OpenPath for MultiLineString<f64> {}
-impl OpenPath for LineString<f64> {}
-impl ClosedPoly for MultiPolygon<f64> {}
-impl ClosedPoly for Polygon<f64> {}
+impl<T: CoordFloat> OpenPath for MultiLineString<T> {}
+impl<T: CoordFloat> OpenPath for LineString<T> {}
+impl<T: CoordFloat> ClosedPoly for MultiPolygon<T> {}
+impl<T: CoordFloat> ClosedPoly for Polygon<T> {}
+
+/// The value Clipper further restricts intermediate products to, roughly
+/// `±4.6e18`, tighter than the full `i64` range of `±9.2e18`. [`suggest_factor`]
+/// targets this bound rather than the full `i64` ceiling.
+///
+/// [`suggest_factor`]: Clipper::suggest_factor
+const CLIPPER_MAX_COORDINATE: f64 = 4.6e18;
+
+/// Reports the largest absolute coordinate value appearing in a geometry, used
+/// by [`Clipper::suggest_factor`] to pick a scale factor that neither overflows
+/// `i64` nor throws away precision on small inputs.
+#[doc(hidden)]
+pub trait BoundingAbsMax {
+    fn bounding_abs_max(&self) -> f64;
+}
+
+impl<T: CoordFloat> BoundingAbsMax for LineString<T> {
+    fn bounding_abs_max(&self) -> f64 {
+        self.0.iter().fold(0.0, |max, coordinate| {
+            max.max(coordinate.x.to_f64().unwrap().abs())
+                .max(coordinate.y.to_f64().unwrap().abs())
+        })
+    }
+}
+
+impl<T: CoordFloat> BoundingAbsMax for MultiLineString<T> {
+    fn bounding_abs_max(&self) -> f64 {
+        self.0
+            .iter()
+            .fold(0.0, |max, line_string| max.max(line_string.bounding_abs_max()))
+    }
+}
+
+impl<T: CoordFloat> BoundingAbsMax for Polygon<T> {
+    fn bounding_abs_max(&self) -> f64 {
+        std::iter::once(self.exterior())
+            .chain(self.interiors().iter())
+            .fold(0.0, |max, line_string| max.max(line_string.bounding_abs_max()))
+    }
+}
+
+impl<T: CoordFloat> BoundingAbsMax for MultiPolygon<T> {
+    fn bounding_abs_max(&self) -> f64 {
+        self.0
+            .iter()
+            .fold(0.0, |max, polygon| max.max(polygon.bounding_abs_max()))
+    }
+}
+
+/// Picks the largest power-of-two scale factor such that `bounding_abs_max * factor`
+/// stays safely under [`CLIPPER_MAX_COORDINATE`]. Power-of-two factors keep the
+/// divide-back step in [`ClipperPath`]'s conversion exact in binary floating point.
+fn largest_power_of_two_factor(bounding_abs_max: f64) -> f64 {
+    if bounding_abs_max <= 0.0 {
+        return 1.0;
+    }
+    // `2f64.powi(max_factor.log2().floor())` is always `<= max_factor`, including when
+    // `max_factor < 1.0` (an oversized input that needs shrinking, not just leaving at 1.0).
+    let max_factor = CLIPPER_MAX_COORDINATE / bounding_abs_max;
+    2f64.powi(max_factor.log2().floor() as i32)
+}
 
 #[doc(hidden)]
 pub struct OwnedPolygon {
@@ -183,11 +402,11 @@ pub struct OwnedPolygon {
     vertices: Vec<Vec<Vec<Vertice>>>,
 }
 
-pub trait ToOwnedPolygon {
+pub trait ToOwnedPolygon<T: CoordFloat> {
     fn to_polygon_owned(&self, poly_type: PolyType, factor: f64) -> OwnedPolygon;
 }
 
-impl ToOwnedPolygon for MultiPolygon<f64> {
+impl<T: CoordFloat> ToOwnedPolygon<T> for MultiPolygon<T> {
     fn to_polygon_owned(&self, poly_type: PolyType, factor: f64) -> OwnedPolygon {
         OwnedPolygon {
             polygons: Vec::with_capacity(self.0.len()),
@@ -198,7 +417,7 @@ impl ToOwnedPolygon for MultiPolygon<f64> {
     }
 }
 
-impl ToOwnedPolygon for Polygon<f64> {
+impl<T: CoordFloat> ToOwnedPolygon<T> for Polygon<T> {
     fn to_polygon_owned(&self, poly_type: PolyType, factor: f64) -> OwnedPolygon {
         OwnedPolygon {
             polygons: Vec::with_capacity(1),
@@ -209,7 +428,7 @@ impl ToOwnedPolygon for Polygon<f64> {
     }
 }
 
-impl ToOwnedPolygon for MultiLineString<f64> {
+impl<T: CoordFloat> ToOwnedPolygon<T> for MultiLineString<T> {
     fn to_polygon_owned(&self, poly_type: PolyType, factor: f64) -> OwnedPolygon {
         OwnedPolygon {
             polygons: Vec::with_capacity(self.0.len()),
@@ -238,7 +457,12 @@ impl OwnedPolygon {
         &self.polygons
     }
 
-    fn add_polygon(mut self, polygon: &Polygon<f64>, poly_type: PolyType, factor: f64) -> Self {
+    fn add_polygon<T: CoordFloat>(
+        mut self,
+        polygon: &Polygon<T>,
+        poly_type: PolyType,
+        factor: f64,
+    ) -> Self {
         let path_count = polygon.interiors().len() + 1;
         self.paths.push(Vec::with_capacity(path_count));
         self.vertices.push(Vec::with_capacity(path_count));
@@ -251,8 +475,8 @@ impl OwnedPolygon {
 
             for coordinate in line_string.0.iter().skip(1) {
                 last_vertices.push([
-                    (coordinate.x * factor) as i64,
-                    (coordinate.y * factor) as i64,
+                    (coordinate.x.to_f64().unwrap() * factor) as i64,
+                    (coordinate.y.to_f64().unwrap() * factor) as i64,
                 ]);
             }
 
@@ -272,9 +496,9 @@ impl OwnedPolygon {
         self
     }
 
-    fn add_line_strings(
+    fn add_line_strings<T: CoordFloat>(
         mut self,
-        line_strings: &MultiLineString<f64>,
+        line_strings: &MultiLineString<T>,
         poly_type: PolyType,
         factor: f64,
     ) -> Self {
@@ -290,8 +514,8 @@ impl OwnedPolygon {
 
             for coordinate in line_string.0.iter() {
                 last_vertices.push([
-                    (coordinate.x * factor) as i64,
-                    (coordinate.y * factor) as i64,
+                    (coordinate.x.to_f64().unwrap() * factor) as i64,
+                    (coordinate.y.to_f64().unwrap() * factor) as i64,
                 ]);
             }
 
@@ -311,20 +535,25 @@ impl OwnedPolygon {
         self
     }
 
-    fn add_polygons(self, polygon: &MultiPolygon<f64>, poly_type: PolyType, factor: f64) -> Self {
+    fn add_polygons<T: CoordFloat>(
+        self,
+        polygon: &MultiPolygon<T>,
+        poly_type: PolyType,
+        factor: f64,
+    ) -> Self {
         polygon.0.iter().fold(self, |polygons, polygon| {
             polygons.add_polygon(polygon, poly_type, factor)
         })
     }
 }
 
-fn execute_offset_operation<T: ToOwnedPolygon + ?Sized>(
-    polygons: &T,
-    delta: f64,
-    jt: JoinType,
-    et: EndType,
-    factor: f64,
-) -> MultiPolygon<f64> {
+/// Fraction of `|delta|` below which an edge produced by the first pass
+/// of [`offset2`](Clipper::offset2) is dropped before the second pass, since
+/// near-zero-length edges otherwise make the join algorithm emit degenerate spikes.
+/// The fraction is fixed and the cleanup always runs; callers cannot change or skip it.
+const SHORTEST_EDGE_FACTOR: f64 = 0.005;
+
+fn raw_offset(clipper_polygons: Polygons, delta: f64, jt: JoinType, et: EndType) -> Polygons {
     let miter_limit = match jt {
         JoinType::Miter(limit) => limit,
         _ => 0.0,
@@ -338,13 +567,7 @@ fn execute_offset_operation<T: ToOwnedPolygon + ?Sized>(
         },
     };
 
-    let mut owned = polygons.to_polygon_owned(PolyType_ptSubject, factor);
-    let mut get_clipper = owned.get_clipper_polygons().clone();
-    let clipper_polygons = Polygons {
-        polygons: get_clipper.as_mut_ptr(),
-        polygons_count: get_clipper.len().try_into().unwrap(),
-    };
-    let solution = unsafe {
+    unsafe {
         offset(
             miter_limit,
             round_precision,
@@ -353,11 +576,31 @@ fn execute_offset_operation<T: ToOwnedPolygon + ?Sized>(
             clipper_polygons,
             delta,
         )
+    }
+}
+
+fn execute_offset_operation<C: CoordFloat, T: ToOwnedPolygon<C> + ?Sized>(
+    polygons: &T,
+    delta: f64,
+    jt: JoinType,
+    et: EndType,
+    factor: f64,
+) -> MultiPolygon<C> {
+    let mut owned = polygons.to_polygon_owned(PolyType_ptSubject, factor);
+    let mut get_clipper = owned.get_clipper_polygons().clone();
+    let clipper_polygons = Polygons {
+        polygons: get_clipper.as_mut_ptr(),
+        polygons_count: get_clipper.len().try_into().unwrap(),
     };
 
+    maybe_dump_from_env(&clipper_polygons);
+
+    let solution = raw_offset(clipper_polygons, delta, jt, et);
+
     let result = ClipperPolygons {
         polygons: solution,
         factor,
+        _marker: PhantomData,
     }
     .into();
     unsafe {
@@ -366,14 +609,119 @@ fn execute_offset_operation<T: ToOwnedPolygon + ?Sized>(
     result
 }
 
+/// Builds an [`OwnedPolygon`] directly from a raw Clipper solution, dropping any
+/// vertex that would form an edge shorter than `min_edge_len` along the way, so
+/// the cleaned-up paths can be fed straight back into a second `offset` pass
+/// without round-tripping through `geo-types`.
+fn owned_polygon_from_solution(solution: &Polygons, min_edge_len: f64) -> OwnedPolygon {
+    let min_edge_len_sq = min_edge_len * min_edge_len;
+    let mut owned = OwnedPolygon {
+        polygons: Vec::new(),
+        paths: Vec::new(),
+        vertices: Vec::new(),
+    };
+
+    for polygon in solution.polygons() {
+        let paths = polygon.paths();
+        owned.paths.push(Vec::with_capacity(paths.len()));
+        owned.vertices.push(Vec::with_capacity(paths.len()));
+        let last_path = owned.paths.last_mut().unwrap();
+        let last_path_vertices = owned.vertices.last_mut().unwrap();
+
+        for path in paths.iter() {
+            let mut cleaned: Vec<Vertice> = Vec::with_capacity(path.vertices().len());
+            for &vertex in path.vertices().iter() {
+                if let Some(&prev) = cleaned.last() {
+                    let dx = (vertex[0] - prev[0]) as f64;
+                    let dy = (vertex[1] - prev[1]) as f64;
+                    if dx * dx + dy * dy < min_edge_len_sq {
+                        continue;
+                    }
+                }
+                cleaned.push(vertex);
+            }
+            if let (Some(&first), Some(&last)) = (cleaned.first(), cleaned.last()) {
+                if cleaned.len() > 1 {
+                    let dx = (last[0] - first[0]) as f64;
+                    let dy = (last[1] - first[1]) as f64;
+                    if dx * dx + dy * dy < min_edge_len_sq {
+                        cleaned.pop();
+                    }
+                }
+            }
+
+            last_path_vertices.push(cleaned);
+            last_path.push(Path {
+                vertices: std::ptr::null_mut(),
+                vertices_count: 0,
+                closed: path.closed,
+            });
+        }
+
+        owned.polygons.push(ClipperPolygon {
+            paths: std::ptr::null_mut(),
+            paths_count: 0,
+            type_: PolyType_ptSubject,
+        });
+    }
+
+    owned
+}
+
+fn execute_offset2_operation<C: CoordFloat, T: ToOwnedPolygon<C> + ?Sized>(
+    polygons: &T,
+    delta1: f64,
+    delta2: f64,
+    jt: JoinType,
+    et: EndType,
+    factor: f64,
+) -> MultiPolygon<C> {
+    let mut owned = polygons.to_polygon_owned(PolyType_ptSubject, factor);
+    let mut get_clipper = owned.get_clipper_polygons().clone();
+    let clipper_polygons = Polygons {
+        polygons: get_clipper.as_mut_ptr(),
+        polygons_count: get_clipper.len().try_into().unwrap(),
+    };
+
+    let first_pass = raw_offset(clipper_polygons, delta1, jt, et);
+
+    let min_edge_len = SHORTEST_EDGE_FACTOR * delta1.abs();
+    let mut cleaned = owned_polygon_from_solution(&first_pass, min_edge_len);
+    unsafe {
+        free_polygons(first_pass);
+    }
+
+    let mut cleaned_clipper = cleaned.get_clipper_polygons().clone();
+    let cleaned_polygons = Polygons {
+        polygons: cleaned_clipper.as_mut_ptr(),
+        polygons_count: cleaned_clipper.len().try_into().unwrap(),
+    };
+
+    let second_pass = raw_offset(cleaned_polygons, delta2, jt, et);
+
+    let result = ClipperPolygons {
+        polygons: second_pass,
+        factor,
+        _marker: PhantomData,
+    }
+    .into();
+    unsafe {
+        free_polygons(second_pass);
+    }
+    result
+}
+
 fn execute_boolean_operation<
-    T: ToOwnedPolygon + ?Sized,
-    U: ToOwnedPolygon + ?Sized,
-    R: From<ClipperPolygons>,
+    C: CoordFloat,
+    T: ToOwnedPolygon<C> + ?Sized,
+    U: ToOwnedPolygon<C> + ?Sized,
+    R: From<ClipperPolygons<C>>,
 >(
     clip_type: ClipType,
     subject_polygons: &T,
     clip_polygons: &U,
+    subject_fill: FillRule,
+    clip_fill: FillRule,
     factor: f64,
 ) -> R {
     let mut subject_owned = subject_polygons.to_polygon_owned(PolyType_ptSubject, factor);
@@ -389,18 +737,100 @@ fn execute_boolean_operation<
         polygons_count: polygons.len().try_into().unwrap(),
     };
 
+    maybe_dump_from_env(&clipper_polygons);
+
+    let solution = unsafe {
+        execute(
+            clip_type,
+            clipper_polygons,
+            subject_fill.into(),
+            clip_fill.into(),
+        )
+    };
+
+    let result = ClipperPolygons {
+        polygons: solution,
+        factor,
+        _marker: PhantomData,
+    }
+    .into();
+    unsafe {
+        free_polygons(solution);
+    }
+    result
+}
+
+fn execute_boolean_operation_nested<
+    C: CoordFloat,
+    T: ToOwnedPolygon<C> + ?Sized,
+    U: ToOwnedPolygon<C> + ?Sized,
+>(
+    clip_type: ClipType,
+    subject_polygons: &T,
+    clip_polygons: &U,
+    subject_fill: FillRule,
+    clip_fill: FillRule,
+    factor: f64,
+) -> MultiPolygon<C> {
+    let mut subject_owned = subject_polygons.to_polygon_owned(PolyType_ptSubject, factor);
+    let mut clip_owned = clip_polygons.to_polygon_owned(PolyType_ptClip, factor);
+    let mut polygons: Vec<ClipperPolygon> = subject_owned
+        .get_clipper_polygons()
+        .iter()
+        .chain(clip_owned.get_clipper_polygons().iter())
+        .cloned()
+        .collect();
+    let clipper_polygons = Polygons {
+        polygons: polygons.as_mut_ptr(),
+        polygons_count: polygons.len().try_into().unwrap(),
+    };
+
+    let solution = unsafe {
+        execute(
+            clip_type,
+            clipper_polygons,
+            subject_fill.into(),
+            clip_fill.into(),
+        )
+    };
+
+    let result = nest_polygons(ClipperPolygons {
+        polygons: solution,
+        factor,
+        _marker: PhantomData,
+    });
+    unsafe {
+        free_polygons(solution);
+    }
+    result
+}
+
+fn execute_boolean_operation_from_owned<C: CoordFloat>(
+    clip_type: ClipType,
+    owned: &mut OwnedPolygon,
+    subject_fill: FillRule,
+    clip_fill: FillRule,
+    factor: f64,
+) -> MultiPolygon<C> {
+    let mut polygons = owned.get_clipper_polygons().clone();
+    let clipper_polygons = Polygons {
+        polygons: polygons.as_mut_ptr(),
+        polygons_count: polygons.len().try_into().unwrap(),
+    };
+
     let solution = unsafe {
         execute(
             clip_type,
             clipper_polygons,
-            PolyFillType_pftNonZero,
-            PolyFillType_pftNonZero,
+            subject_fill.into(),
+            clip_fill.into(),
         )
     };
 
     let result = ClipperPolygons {
         polygons: solution,
         factor,
+        _marker: PhantomData,
     }
     .into();
     unsafe {
@@ -409,96 +839,314 @@ fn execute_boolean_operation<
     result
 }
 
+/// Accumulates an arbitrary number of subject and clip paths, both open and
+/// closed, into a single Clipper execution, instead of the one-subject/one-clip
+/// calls on [`Clipper`] and [`ClipperOpen`] which cannot express a three-or-more-way
+/// overlay and pay the cost of a separate FFI round-trip per pair.
+pub struct ClipperBuilder {
+    owned: OwnedPolygon,
+    factor: Option<f64>,
+}
+
+impl ClipperBuilder {
+    pub fn new() -> Self {
+        ClipperBuilder {
+            owned: OwnedPolygon {
+                polygons: Vec::new(),
+                paths: Vec::new(),
+                vertices: Vec::new(),
+            },
+            factor: None,
+        }
+    }
+
+    /// Every `add_*` call and `execute` must agree on the same `factor`, since it
+    /// scales paths to integers going in and divides the solution back down coming
+    /// out; mixing factors would silently return corrupted geometry. Panics if a
+    /// call passes a `factor` that differs from the one already in use.
+    fn check_factor(&mut self, factor: f64) {
+        match self.factor {
+            Some(existing) => assert_eq!(
+                existing, factor,
+                "ClipperBuilder: factor {} does not match {} already used for paths added to this builder",
+                factor, existing
+            ),
+            None => self.factor = Some(factor),
+        }
+    }
+
+    /// Adds a closed polygon as a subject path.
+    pub fn add_subject<C: CoordFloat, T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &mut self,
+        geometry: &T,
+        factor: f64,
+    ) -> &mut Self {
+        self.check_factor(factor);
+        self.merge(geometry.to_polygon_owned(PolyType_ptSubject, factor));
+        self
+    }
+
+    /// Adds a closed polygon as a clip path.
+    pub fn add_clip<C: CoordFloat, T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &mut self,
+        geometry: &T,
+        factor: f64,
+    ) -> &mut Self {
+        self.check_factor(factor);
+        self.merge(geometry.to_polygon_owned(PolyType_ptClip, factor));
+        self
+    }
+
+    /// Adds an open path (a line or multi-line string) as a subject path.
+    pub fn add_open_subject<C: CoordFloat, T: ToOwnedPolygon<C> + OpenPath + ?Sized>(
+        &mut self,
+        geometry: &T,
+        factor: f64,
+    ) -> &mut Self {
+        self.check_factor(factor);
+        self.merge(geometry.to_polygon_owned(PolyType_ptSubject, factor));
+        self
+    }
+
+    fn merge(&mut self, mut other: OwnedPolygon) {
+        self.owned.polygons.append(&mut other.polygons);
+        self.owned.paths.append(&mut other.paths);
+        self.owned.vertices.append(&mut other.vertices);
+    }
+
+    /// Runs `op` once across every subject and clip path added so far.
+    pub fn execute<C: CoordFloat>(&mut self, op: BooleanOp, factor: f64) -> MultiPolygon<C> {
+        self.check_factor(factor);
+        execute_boolean_operation_from_owned(
+            op.into(),
+            &mut self.owned,
+            FillRule::NonZero,
+            FillRule::NonZero,
+            factor,
+        )
+    }
+}
+
+impl Default for ClipperBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// This trait defines the boolean and offset operations on polygons
 ///
 /// The `factor` parameter in its methods is used to scale shapes before and after applying the operation
 /// to avoid precision loss since Clipper (the underlaying library) performs integer computation.
-pub trait Clipper {
-    fn difference<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+pub trait Clipper<C: CoordFloat> {
+    fn difference<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
         factor: f64,
-    ) -> MultiPolygon<f64>;
-    fn intersection<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    ) -> MultiPolygon<C>;
+    fn intersection<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
         factor: f64,
-    ) -> MultiPolygon<f64>;
-    fn union<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    ) -> MultiPolygon<C>;
+    fn union<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
         factor: f64,
-    ) -> MultiPolygon<f64>;
-    fn xor<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    ) -> MultiPolygon<C>;
+    fn xor<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
         factor: f64,
-    ) -> MultiPolygon<f64>;
+    ) -> MultiPolygon<C>;
     fn offset(
         &self,
         delta: f64,
         join_type: JoinType,
         end_type: EndType,
         factor: f64,
-    ) -> MultiPolygon<f64>;
-}
-
-/// This trait defines the boolean and offset operations between open paths and polygons
-/// It is a subset of the operations for polygons
-///
-/// The `factor` parameter in its methods is used to scale shapes before and after applying the boolean operation
-/// to avoid precision loss since Clipper (the underlaying library) performs integer computation.
-pub trait ClipperOpen {
-    fn difference<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    ) -> MultiPolygon<C>;
+    fn difference_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
         factor: f64,
-    ) -> MultiLineString<f64>;
-    fn intersection<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    ) -> MultiPolygon<C>;
+    fn intersection_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
         factor: f64,
-    ) -> MultiLineString<f64>;
-    fn offset(
+    ) -> MultiPolygon<C>;
+    fn union_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
-        delta: f64,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiPolygon<C>;
+    fn xor_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiPolygon<C>;
+    /// Applies two sequential offsets with the same join/end settings (e.g.
+    /// `+delta` then `-delta` for a morphological closing, or the reverse for an
+    /// opening) to smooth corners and eliminate slivers that a single offset
+    /// leaves behind. Edges shorter than `SHORTEST_EDGE_FACTOR * |delta1| * factor`
+    /// produced by the first pass are dropped before the second pass runs.
+    fn offset2(
+        &self,
+        delta1: f64,
+        delta2: f64,
         join_type: JoinType,
         end_type: EndType,
         factor: f64,
-    ) -> MultiPolygon<f64>;
-}
-
-impl<U: ToOwnedPolygon + ClosedPoly + ?Sized> Clipper for U {
-    fn difference<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    ) -> MultiPolygon<C>;
+    /// Like [`intersection`](Clipper::intersection), but rebuilds the outer/hole
+    /// hierarchy of the result the way Clipper's own PolyTree conversion does,
+    /// instead of assuming each returned polygon's first path is its only outer.
+    /// Prefer this over `intersection` when the operands can produce several
+    /// outer contours alongside holes, since the flat behavior can mis-assign
+    /// holes to the wrong outer or drop outers entirely.
+    fn intersection_tree<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
         factor: f64,
-    ) -> MultiPolygon<f64> {
-        execute_boolean_operation(ClipType_ctDifference, self, other, factor)
-    }
-
-    fn intersection<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    ) -> MultiPolygon<C>;
+    /// Suggests the largest power-of-two `factor` that keeps this geometry's
+    /// coordinates safely under the bound Clipper restricts intermediate products
+    /// to, without throwing away precision on small inputs. Useful as the `factor`
+    /// argument to the other methods on this trait when the operands' scale isn't
+    /// known ahead of time.
+    fn suggest_factor(&self) -> f64
+    where
+        Self: BoundingAbsMax;
+    /// Like [`difference`](Clipper::difference), but computes `factor` from the
+    /// combined bounding box of `self` and `other` via [`suggest_factor`](Clipper::suggest_factor)
+    /// instead of taking it as a parameter.
+    fn difference_auto<T: ToOwnedPolygon<C> + ClosedPoly + BoundingAbsMax + ?Sized>(
         &self,
         other: &T,
+    ) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax;
+    /// Like [`intersection`](Clipper::intersection), but computes `factor` from the
+    /// combined bounding box of `self` and `other` via [`suggest_factor`](Clipper::suggest_factor)
+    /// instead of taking it as a parameter.
+    fn intersection_auto<T: ToOwnedPolygon<C> + ClosedPoly + BoundingAbsMax + ?Sized>(
+        &self,
+        other: &T,
+    ) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax;
+    /// Like [`union`](Clipper::union), but computes `factor` from the combined
+    /// bounding box of `self` and `other` via [`suggest_factor`](Clipper::suggest_factor)
+    /// instead of taking it as a parameter.
+    fn union_auto<T: ToOwnedPolygon<C> + ClosedPoly + BoundingAbsMax + ?Sized>(
+        &self,
+        other: &T,
+    ) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax;
+    /// Like [`xor`](Clipper::xor), but computes `factor` from the combined bounding
+    /// box of `self` and `other` via [`suggest_factor`](Clipper::suggest_factor)
+    /// instead of taking it as a parameter.
+    fn xor_auto<T: ToOwnedPolygon<C> + ClosedPoly + BoundingAbsMax + ?Sized>(
+        &self,
+        other: &T,
+    ) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax;
+    /// Like [`offset`](Clipper::offset), but computes `factor` from `self`'s
+    /// bounding box (padded for `delta`) via [`suggest_factor`](Clipper::suggest_factor)
+    /// instead of taking it as a parameter.
+    fn offset_auto(&self, delta: f64, join_type: JoinType, end_type: EndType) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax;
+}
+
+/// This trait defines the boolean and offset operations between open paths and polygons
+/// It is a subset of the operations for polygons
+///
+/// The `factor` parameter in its methods is used to scale shapes before and after applying the boolean operation
+/// to avoid precision loss since Clipper (the underlaying library) performs integer computation.
+pub trait ClipperOpen<C: CoordFloat> {
+    fn difference<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        factor: f64,
+    ) -> MultiLineString<C>;
+    fn intersection<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        factor: f64,
+    ) -> MultiLineString<C>;
+    fn offset(
+        &self,
+        delta: f64,
+        join_type: JoinType,
+        end_type: EndType,
         factor: f64,
-    ) -> MultiPolygon<f64> {
-        execute_boolean_operation(ClipType_ctIntersection, self, other, factor)
+    ) -> MultiPolygon<C>;
+    fn offset2(
+        &self,
+        delta1: f64,
+        delta2: f64,
+        join_type: JoinType,
+        end_type: EndType,
+        factor: f64,
+    ) -> MultiPolygon<C>;
+    fn difference_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiLineString<C>;
+    fn intersection_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiLineString<C>;
+}
+
+impl<C: CoordFloat, U: ToOwnedPolygon<C> + ClosedPoly + ?Sized> Clipper<C> for U {
+    fn difference<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        factor: f64,
+    ) -> MultiPolygon<C> {
+        self.difference_with_fill(other, FillRule::NonZero, FillRule::NonZero, factor)
+    }
+
+    fn intersection<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        factor: f64,
+    ) -> MultiPolygon<C> {
+        self.intersection_with_fill(other, FillRule::NonZero, FillRule::NonZero, factor)
     }
 
-    fn union<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    fn union<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
         factor: f64,
-    ) -> MultiPolygon<f64> {
-        execute_boolean_operation(ClipType_ctUnion, self, other, factor)
+    ) -> MultiPolygon<C> {
+        self.union_with_fill(other, FillRule::NonZero, FillRule::NonZero, factor)
     }
 
-    fn xor<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    fn xor<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
         factor: f64,
-    ) -> MultiPolygon<f64> {
-        execute_boolean_operation(ClipType_ctXor, self, other, factor)
+    ) -> MultiPolygon<C> {
+        self.xor_with_fill(other, FillRule::NonZero, FillRule::NonZero, factor)
     }
 
     fn offset(
@@ -507,26 +1155,165 @@ impl<U: ToOwnedPolygon + ClosedPoly + ?Sized> Clipper for U {
         join_type: JoinType,
         end_type: EndType,
         factor: f64,
-    ) -> MultiPolygon<f64> {
+    ) -> MultiPolygon<C> {
         execute_offset_operation(self, delta * factor, join_type, end_type, factor)
     }
+
+    fn offset2(
+        &self,
+        delta1: f64,
+        delta2: f64,
+        join_type: JoinType,
+        end_type: EndType,
+        factor: f64,
+    ) -> MultiPolygon<C> {
+        execute_offset2_operation(
+            self,
+            delta1 * factor,
+            delta2 * factor,
+            join_type,
+            end_type,
+            factor,
+        )
+    }
+
+    fn difference_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiPolygon<C> {
+        execute_boolean_operation(ClipType_ctDifference, self, other, subj_fill, clip_fill, factor)
+    }
+
+    fn intersection_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiPolygon<C> {
+        execute_boolean_operation(
+            ClipType_ctIntersection,
+            self,
+            other,
+            subj_fill,
+            clip_fill,
+            factor,
+        )
+    }
+
+    fn union_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiPolygon<C> {
+        execute_boolean_operation(ClipType_ctUnion, self, other, subj_fill, clip_fill, factor)
+    }
+
+    fn xor_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiPolygon<C> {
+        execute_boolean_operation(ClipType_ctXor, self, other, subj_fill, clip_fill, factor)
+    }
+
+    fn intersection_tree<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        factor: f64,
+    ) -> MultiPolygon<C> {
+        execute_boolean_operation_nested(
+            ClipType_ctIntersection,
+            self,
+            other,
+            FillRule::NonZero,
+            FillRule::NonZero,
+            factor,
+        )
+    }
+
+    fn suggest_factor(&self) -> f64
+    where
+        Self: BoundingAbsMax,
+    {
+        largest_power_of_two_factor(self.bounding_abs_max())
+    }
+
+    fn difference_auto<T: ToOwnedPolygon<C> + ClosedPoly + BoundingAbsMax + ?Sized>(
+        &self,
+        other: &T,
+    ) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax,
+    {
+        let factor = largest_power_of_two_factor(self.bounding_abs_max().max(other.bounding_abs_max()));
+        self.difference(other, factor)
+    }
+
+    fn intersection_auto<T: ToOwnedPolygon<C> + ClosedPoly + BoundingAbsMax + ?Sized>(
+        &self,
+        other: &T,
+    ) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax,
+    {
+        let factor = largest_power_of_two_factor(self.bounding_abs_max().max(other.bounding_abs_max()));
+        self.intersection(other, factor)
+    }
+
+    fn union_auto<T: ToOwnedPolygon<C> + ClosedPoly + BoundingAbsMax + ?Sized>(
+        &self,
+        other: &T,
+    ) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax,
+    {
+        let factor = largest_power_of_two_factor(self.bounding_abs_max().max(other.bounding_abs_max()));
+        self.union(other, factor)
+    }
+
+    fn xor_auto<T: ToOwnedPolygon<C> + ClosedPoly + BoundingAbsMax + ?Sized>(
+        &self,
+        other: &T,
+    ) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax,
+    {
+        let factor = largest_power_of_two_factor(self.bounding_abs_max().max(other.bounding_abs_max()));
+        self.xor(other, factor)
+    }
+
+    fn offset_auto(&self, delta: f64, join_type: JoinType, end_type: EndType) -> MultiPolygon<C>
+    where
+        Self: BoundingAbsMax,
+    {
+        let factor = largest_power_of_two_factor(self.bounding_abs_max() + delta.abs());
+        self.offset(delta, join_type, end_type, factor)
+    }
 }
 
-impl<U: ToOwnedPolygon + OpenPath + ?Sized> ClipperOpen for U {
-    fn difference<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+impl<C: CoordFloat, U: ToOwnedPolygon<C> + OpenPath + ?Sized> ClipperOpen<C> for U {
+    fn difference<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
         factor: f64,
-    ) -> MultiLineString<f64> {
-        execute_boolean_operation(ClipType_ctDifference, self, other, factor)
+    ) -> MultiLineString<C> {
+        self.difference_with_fill(other, FillRule::NonZero, FillRule::NonZero, factor)
     }
 
-    fn intersection<T: ToOwnedPolygon + ClosedPoly + ?Sized>(
+    fn intersection<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
         &self,
         other: &T,
         factor: f64,
-    ) -> MultiLineString<f64> {
-        execute_boolean_operation(ClipType_ctIntersection, self, other, factor)
+    ) -> MultiLineString<C> {
+        self.intersection_with_fill(other, FillRule::NonZero, FillRule::NonZero, factor)
     }
 
     fn offset(
@@ -535,11 +1322,221 @@ impl<U: ToOwnedPolygon + OpenPath + ?Sized> ClipperOpen for U {
         join_type: JoinType,
         end_type: EndType,
         factor: f64,
-    ) -> MultiPolygon<f64> {
+    ) -> MultiPolygon<C> {
         execute_offset_operation(self, delta * factor, join_type, end_type, factor)
     }
+
+    fn offset2(
+        &self,
+        delta1: f64,
+        delta2: f64,
+        join_type: JoinType,
+        end_type: EndType,
+        factor: f64,
+    ) -> MultiPolygon<C> {
+        execute_offset2_operation(
+            self,
+            delta1 * factor,
+            delta2 * factor,
+            join_type,
+            end_type,
+            factor,
+        )
+    }
+
+    fn difference_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiLineString<C> {
+        execute_boolean_operation(ClipType_ctDifference, self, other, subj_fill, clip_fill, factor)
+    }
+
+    fn intersection_with_fill<T: ToOwnedPolygon<C> + ClosedPoly + ?Sized>(
+        &self,
+        other: &T,
+        subj_fill: FillRule,
+        clip_fill: FillRule,
+        factor: f64,
+    ) -> MultiLineString<C> {
+        execute_boolean_operation(
+            ClipType_ctIntersection,
+            self,
+            other,
+            subj_fill,
+            clip_fill,
+            factor,
+        )
+    }
+}
+
+/// Binary dump of scaled integer paths for offline crash reproduction, gated
+/// behind the `dump` feature since it is only useful while minimizing a
+/// pathological input for a Clipper bug report.
+#[cfg(feature = "dump")]
+pub mod dump {
+    use super::{ClipperPolygon, OwnedPolygon, Path, PolyType, Polygons, Vertice};
+    use std::fs::File;
+    use std::io::{self, Read, Write};
+    use std::path::Path as FsPath;
+
+    /// Serializes the scaled integer paths of `polygons` to a binary layout derived
+    /// from the one slicers use for Clipper bug reports, extended with a `u32`
+    /// [`PolyType`] tag per top-level polygon so subject and clip paths can be told
+    /// apart on replay: a `u32` polygon count, then per polygon its `type_` tag, a
+    /// `u32` path count, and per path a `u32` `closed` flag, a `u32` vertex count,
+    /// and the raw `[i64; 2]` vertices. `closed` is dumped per path since
+    /// `ClipperOpen`'s subject paths are open and would otherwise replay closed.
+    pub fn dump_to(polygons: &Polygons, path: impl AsRef<FsPath>) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        let clipper_polygons = polygons.polygons();
+
+        file.write_all(&(clipper_polygons.len() as u32).to_le_bytes())?;
+        for polygon in clipper_polygons {
+            file.write_all(&(polygon.type_ as u32).to_le_bytes())?;
+            let paths = polygon.paths();
+            file.write_all(&(paths.len() as u32).to_le_bytes())?;
+            for path in paths {
+                file.write_all(&(path.closed as u32).to_le_bytes())?;
+                let vertices = path.vertices();
+                file.write_all(&(vertices.len() as u32).to_le_bytes())?;
+                for vertex in vertices.iter() {
+                    file.write_all(&vertex[0].to_le_bytes())?;
+                    file.write_all(&vertex[1].to_le_bytes())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reconstructs an [`OwnedPolygon`] from a file written by [`dump_to`], with
+    /// each top-level polygon's subject/clip [`PolyType`] restored from the file
+    /// rather than assumed by the caller.
+    pub fn load_from(path: impl AsRef<FsPath>) -> io::Result<OwnedPolygon> {
+        let mut file = File::open(path)?;
+
+        let polygon_count = read_u32(&mut file)? as usize;
+        let mut owned = OwnedPolygon {
+            polygons: Vec::with_capacity(polygon_count),
+            paths: Vec::with_capacity(polygon_count),
+            vertices: Vec::with_capacity(polygon_count),
+        };
+
+        for _ in 0..polygon_count {
+            let poly_type: PolyType = read_u32(&mut file)?;
+            let path_count = read_u32(&mut file)? as usize;
+            let mut paths = Vec::with_capacity(path_count);
+            let mut vertices = Vec::with_capacity(path_count);
+
+            for _ in 0..path_count {
+                let closed = read_u32(&mut file)?;
+                let vertex_count = read_u32(&mut file)? as usize;
+                let mut path_vertices: Vec<Vertice> = Vec::with_capacity(vertex_count);
+                for _ in 0..vertex_count {
+                    path_vertices.push([read_i64(&mut file)?, read_i64(&mut file)?]);
+                }
+                vertices.push(path_vertices);
+                paths.push(Path {
+                    vertices: std::ptr::null_mut(),
+                    vertices_count: 0,
+                    closed: closed as _,
+                });
+            }
+
+            owned.polygons.push(ClipperPolygon {
+                paths: std::ptr::null_mut(),
+                paths_count: 0,
+                type_: poly_type,
+            });
+            owned.paths.push(paths);
+            owned.vertices.push(vertices);
+        }
+
+        Ok(owned)
+    }
+
+    fn read_u32(file: &mut File) -> io::Result<u32> {
+        let mut buf = [0u8; 4];
+        file.read_exact(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn read_i64(file: &mut File) -> io::Result<i64> {
+        let mut buf = [0u8; 8];
+        file.read_exact(&mut buf)?;
+        Ok(i64::from_le_bytes(buf))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::PolyType_ptSubject;
+        use std::env::temp_dir;
+
+        #[test]
+        fn test_dump_round_trip() {
+            let mut vertices_a: Vec<Vertice> = vec![[0, 0], [10, 0], [10, 10], [0, 10]];
+            let mut vertices_b: Vec<Vertice> = vec![[100, 100], [200, 100]];
+
+            let mut paths = vec![
+                Path {
+                    vertices: vertices_a.as_mut_ptr(),
+                    vertices_count: vertices_a.len().try_into().unwrap(),
+                    closed: 1,
+                },
+                Path {
+                    vertices: vertices_b.as_mut_ptr(),
+                    vertices_count: vertices_b.len().try_into().unwrap(),
+                    closed: 0,
+                },
+            ];
+
+            let mut clipper_polygons = vec![ClipperPolygon {
+                paths: paths.as_mut_ptr(),
+                paths_count: paths.len().try_into().unwrap(),
+                type_: PolyType_ptSubject,
+            }];
+
+            let polygons = Polygons {
+                polygons: clipper_polygons.as_mut_ptr(),
+                polygons_count: clipper_polygons.len().try_into().unwrap(),
+            };
+
+            let file_path = temp_dir().join("geo_clipper_dump_round_trip_test.bin");
+            dump_to(&polygons, &file_path).unwrap();
+            let loaded = load_from(&file_path).unwrap();
+            let _ = std::fs::remove_file(&file_path);
+
+            assert_eq!(loaded.polygons.len(), 1);
+            assert_eq!(loaded.polygons[0].type_, PolyType_ptSubject);
+            assert_eq!(loaded.paths[0].len(), 2);
+            assert_eq!(loaded.vertices[0][0], vertices_a);
+            assert_eq!(loaded.vertices[0][1], vertices_b);
+            assert_eq!(loaded.paths[0][0].closed, 1);
+            assert_eq!(loaded.paths[0][1].closed, 0);
+        }
+    }
 }
 
+/// If the `CLIPPER_DUMP` environment variable is set, dumps `polygons` to the
+/// path it names, so a crash or bad result can be replayed and minimized offline
+/// with [`dump::load_from`]. It runs before the boolean operations of [`Clipper`]
+/// and [`ClipperOpen`] (including their `_with_fill` and `_auto` forms) and before
+/// `offset`; `intersection_tree`, `offset2` and [`ClipperBuilder::execute`] do not dump.
+#[cfg(feature = "dump")]
+fn maybe_dump_from_env(polygons: &Polygons) {
+    if let Ok(path) = std::env::var("CLIPPER_DUMP") {
+        if let Err(err) = dump::dump_to(polygons, &path) {
+            eprintln!("geo-clipper: failed to write CLIPPER_DUMP to {path}: {err}");
+        }
+    }
+}
+
+#[cfg(not(feature = "dump"))]
+fn maybe_dump_from_env(_polygons: &Polygons) {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -677,4 +1674,399 @@ mod tests {
         let result = subject.offset(5.0, JoinType::Miter(5.0), EndType::OpenSquare, 1.0);
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn test_intersection_tree() {
+        // Same fixture as `test_closed_clip`, but scaled through a large factor so a
+        // regression to `i64` arithmetic in `path_signed_area`/`path_contains_point`
+        // would overflow rather than just produce a slightly different nesting.
+        let expected = MultiPolygon(vec![Polygon::new(
+            LineString(vec![
+                Coordinate { x: 240.0, y: 200.0 },
+                Coordinate { x: 190.0, y: 200.0 },
+                Coordinate { x: 190.0, y: 150.0 },
+                Coordinate { x: 240.0, y: 150.0 },
+            ]),
+            vec![LineString(vec![
+                Coordinate { x: 200.0, y: 190.0 },
+                Coordinate { x: 230.0, y: 190.0 },
+                Coordinate { x: 215.0, y: 160.0 },
+            ])],
+        )]);
+
+        let subject = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 180.0, y: 200.0 },
+                Coordinate { x: 260.0, y: 200.0 },
+                Coordinate { x: 260.0, y: 150.0 },
+                Coordinate { x: 180.0, y: 150.0 },
+            ]),
+            vec![LineString(vec![
+                Coordinate { x: 215.0, y: 160.0 },
+                Coordinate { x: 230.0, y: 190.0 },
+                Coordinate { x: 200.0, y: 190.0 },
+            ])],
+        );
+
+        let clip = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 190.0, y: 210.0 },
+                Coordinate { x: 240.0, y: 210.0 },
+                Coordinate { x: 240.0, y: 130.0 },
+                Coordinate { x: 190.0, y: 130.0 },
+            ]),
+            vec![],
+        );
+
+        let result = subject.intersection_tree(&clip, 1e8);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_offset2() {
+        // Expanding then shrinking a convex rectangle by the same delta with a
+        // miter join is an exact round trip; a `factor` of 2.0 checks that both
+        // deltas are scaled and the result is divided back down. The rectangle's
+        // edges are far longer than the short-edge cleanup threshold, so the
+        // cleanup drops nothing here.
+        let rect = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 100.0, y: 100.0 },
+                Coordinate { x: 200.0, y: 100.0 },
+                Coordinate { x: 200.0, y: 200.0 },
+                Coordinate { x: 100.0, y: 200.0 },
+            ]),
+            vec![],
+        );
+        let expected = MultiPolygon(vec![rect.clone()]);
+
+        let result = rect.offset2(
+            10.0,
+            -10.0,
+            JoinType::Miter(5.0),
+            EndType::ClosedPolygon,
+            2.0,
+        );
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_clipper_builder() {
+        // Two overlapping subject rectangles union into one wide rectangle, and two
+        // overlapping clip rectangles union into a region that fully covers it, so
+        // intersecting the two merged groups should return the subject union intact.
+        let subject_a = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 190.0, y: 0.0 },
+                Coordinate { x: 190.0, y: 100.0 },
+                Coordinate { x: 0.0, y: 100.0 },
+            ]),
+            vec![],
+        );
+        let subject_b = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 90.0, y: 0.0 },
+                Coordinate { x: 280.0, y: 0.0 },
+                Coordinate { x: 280.0, y: 100.0 },
+                Coordinate { x: 90.0, y: 100.0 },
+            ]),
+            vec![],
+        );
+        let clip_a = Polygon::new(
+            LineString(vec![
+                Coordinate { x: -10.0, y: -10.0 },
+                Coordinate { x: 150.0, y: -10.0 },
+                Coordinate { x: 150.0, y: 110.0 },
+                Coordinate { x: -10.0, y: 110.0 },
+            ]),
+            vec![],
+        );
+        let clip_b = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 140.0, y: -10.0 },
+                Coordinate { x: 300.0, y: -10.0 },
+                Coordinate { x: 300.0, y: 110.0 },
+                Coordinate { x: 140.0, y: 110.0 },
+            ]),
+            vec![],
+        );
+
+        let expected = MultiPolygon(vec![Polygon::new(
+            LineString(vec![
+                Coordinate { x: 280.0, y: 0.0 },
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 0.0, y: 100.0 },
+                Coordinate { x: 280.0, y: 100.0 },
+            ]),
+            vec![],
+        )]);
+
+        let mut builder = ClipperBuilder::new();
+        let result: MultiPolygon<f64> = builder
+            .add_subject(&subject_a, 1.0)
+            .add_subject(&subject_b, 1.0)
+            .add_clip(&clip_a, 1.0)
+            .add_clip(&clip_b, 1.0)
+            .execute(BooleanOp::Intersection, 1.0);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clipper_builder_factor_mismatch_panics() {
+        let subject = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 10.0, y: 0.0 },
+                Coordinate { x: 10.0, y: 10.0 },
+                Coordinate { x: 0.0, y: 10.0 },
+            ]),
+            vec![],
+        );
+        let clip = subject.clone();
+
+        let mut builder = ClipperBuilder::new();
+        builder.add_subject(&subject, 1.0).add_clip(&clip, 2.0);
+    }
+
+    #[test]
+    fn test_largest_power_of_two_factor_zero() {
+        assert_eq!(largest_power_of_two_factor(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_largest_power_of_two_factor_normal() {
+        let factor = largest_power_of_two_factor(100.0);
+        assert_eq!(factor, 2f64.powi(55));
+        assert!(100.0 * factor <= CLIPPER_MAX_COORDINATE);
+        assert!(100.0 * factor * 2.0 > CLIPPER_MAX_COORDINATE);
+    }
+
+    #[test]
+    fn test_largest_power_of_two_factor_oversized() {
+        // Regression test for 3a21d11: a bounding box already past
+        // `CLIPPER_MAX_COORDINATE` must shrink below 1.0, not fall back to 1.0.
+        let bounding_abs_max = 1e19;
+        let factor = largest_power_of_two_factor(bounding_abs_max);
+        assert!(factor < 1.0);
+        assert!(bounding_abs_max * factor <= CLIPPER_MAX_COORDINATE);
+    }
+
+    #[test]
+    fn test_largest_power_of_two_factor_tiny() {
+        let bounding_abs_max = 1e-10;
+        let factor = largest_power_of_two_factor(bounding_abs_max);
+        assert!(bounding_abs_max * factor <= CLIPPER_MAX_COORDINATE);
+        assert!(bounding_abs_max * factor * 2.0 > CLIPPER_MAX_COORDINATE);
+    }
+
+    #[test]
+    fn test_suggest_factor() {
+        let polygon = Polygon::new(
+            LineString(vec![
+                Coordinate { x: -100.0, y: -100.0 },
+                Coordinate { x: 100.0, y: -100.0 },
+                Coordinate { x: 100.0, y: 100.0 },
+                Coordinate { x: -100.0, y: 100.0 },
+            ]),
+            vec![],
+        );
+
+        assert_eq!(polygon.suggest_factor(), largest_power_of_two_factor(100.0));
+    }
+
+    #[test]
+    fn test_intersection_auto() {
+        // Same fixture as `test_closed_clip`, driven through the `_auto` wiring
+        // instead of a caller-supplied factor.
+        let expected = MultiPolygon(vec![Polygon::new(
+            LineString(vec![
+                Coordinate { x: 240.0, y: 200.0 },
+                Coordinate { x: 190.0, y: 200.0 },
+                Coordinate { x: 190.0, y: 150.0 },
+                Coordinate { x: 240.0, y: 150.0 },
+            ]),
+            vec![LineString(vec![
+                Coordinate { x: 200.0, y: 190.0 },
+                Coordinate { x: 230.0, y: 190.0 },
+                Coordinate { x: 215.0, y: 160.0 },
+            ])],
+        )]);
+
+        let subject = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 180.0, y: 200.0 },
+                Coordinate { x: 260.0, y: 200.0 },
+                Coordinate { x: 260.0, y: 150.0 },
+                Coordinate { x: 180.0, y: 150.0 },
+            ]),
+            vec![LineString(vec![
+                Coordinate { x: 215.0, y: 160.0 },
+                Coordinate { x: 230.0, y: 190.0 },
+                Coordinate { x: 200.0, y: 190.0 },
+            ])],
+        );
+
+        let clip = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 190.0, y: 210.0 },
+                Coordinate { x: 240.0, y: 210.0 },
+                Coordinate { x: 240.0, y: 130.0 },
+                Coordinate { x: 190.0, y: 130.0 },
+            ]),
+            vec![],
+        );
+
+        let result = subject.intersection_auto(&clip);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_f32_clip() {
+        // Same fixture as `test_closed_clip`, but over `Polygon<f32>` to confirm the
+        // `CoordFloat` generalization actually round-trips through `T::from(...).unwrap()`
+        // for a non-`f64` coordinate type, not just that it type-checks.
+        let expected: MultiPolygon<f32> = MultiPolygon(vec![Polygon::new(
+            LineString(vec![
+                Coordinate { x: 240.0, y: 200.0 },
+                Coordinate { x: 190.0, y: 200.0 },
+                Coordinate { x: 190.0, y: 150.0 },
+                Coordinate { x: 240.0, y: 150.0 },
+            ]),
+            vec![LineString(vec![
+                Coordinate { x: 200.0, y: 190.0 },
+                Coordinate { x: 230.0, y: 190.0 },
+                Coordinate { x: 215.0, y: 160.0 },
+            ])],
+        )]);
+
+        let subject: Polygon<f32> = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 180.0, y: 200.0 },
+                Coordinate { x: 260.0, y: 200.0 },
+                Coordinate { x: 260.0, y: 150.0 },
+                Coordinate { x: 180.0, y: 150.0 },
+            ]),
+            vec![LineString(vec![
+                Coordinate { x: 215.0, y: 160.0 },
+                Coordinate { x: 230.0, y: 190.0 },
+                Coordinate { x: 200.0, y: 190.0 },
+            ])],
+        );
+
+        let clip: Polygon<f32> = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 190.0, y: 210.0 },
+                Coordinate { x: 240.0, y: 210.0 },
+                Coordinate { x: 240.0, y: 130.0 },
+                Coordinate { x: 190.0, y: 130.0 },
+            ]),
+            vec![],
+        );
+
+        let result = subject.intersection(&clip, 1.0);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_fill_rule_non_zero_vs_even_odd() {
+        // Two same-winding (CCW) overlapping squares: the overlap has winding count
+        // 2. `NonZero` keeps it solid (2 != 0), `EvenOdd` treats it as a hole (2 is
+        // even), exactly the self-intersecting-ring distinction the enum documents.
+        let square_a = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 100.0, y: 0.0 },
+                Coordinate { x: 100.0, y: 100.0 },
+                Coordinate { x: 0.0, y: 100.0 },
+            ]),
+            vec![],
+        );
+        let square_b = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 50.0, y: 50.0 },
+                Coordinate { x: 150.0, y: 50.0 },
+                Coordinate { x: 150.0, y: 150.0 },
+                Coordinate { x: 50.0, y: 150.0 },
+            ]),
+            vec![],
+        );
+        let subject = MultiPolygon(vec![square_a, square_b]);
+
+        let clip = Polygon::new(
+            LineString(vec![
+                Coordinate { x: -10.0, y: -10.0 },
+                Coordinate { x: 200.0, y: -10.0 },
+                Coordinate { x: 200.0, y: 200.0 },
+                Coordinate { x: -10.0, y: 200.0 },
+            ]),
+            vec![],
+        );
+
+        let non_zero =
+            subject.intersection_with_fill(&clip, FillRule::NonZero, FillRule::NonZero, 1.0);
+        assert_eq!(non_zero.0.len(), 1);
+        assert_eq!(non_zero.0[0].interiors().len(), 0);
+
+        let even_odd =
+            subject.intersection_with_fill(&clip, FillRule::EvenOdd, FillRule::NonZero, 1.0);
+        assert_eq!(even_odd.0.len(), 1);
+        assert_eq!(even_odd.0[0].interiors().len(), 1);
+    }
+
+    #[test]
+    fn test_fill_rule_positive_vs_negative() {
+        // A CCW square (winding +1) overlapping a CW square (winding -1) leaves the
+        // overlap at winding 0, so `Positive`/`Negative` each keep only one square's
+        // exclusive region, letting ring orientation pick which survives.
+        let square_a = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 100.0, y: 0.0 },
+                Coordinate { x: 100.0, y: 100.0 },
+                Coordinate { x: 0.0, y: 100.0 },
+            ]),
+            vec![],
+        );
+        let square_b = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 50.0, y: 150.0 },
+                Coordinate { x: 150.0, y: 150.0 },
+                Coordinate { x: 150.0, y: 50.0 },
+                Coordinate { x: 50.0, y: 50.0 },
+            ]),
+            vec![],
+        );
+        let subject = MultiPolygon(vec![square_a, square_b]);
+
+        let clip = Polygon::new(
+            LineString(vec![
+                Coordinate { x: -10.0, y: -10.0 },
+                Coordinate { x: 200.0, y: -10.0 },
+                Coordinate { x: 200.0, y: 200.0 },
+                Coordinate { x: -10.0, y: 200.0 },
+            ]),
+            vec![],
+        );
+
+        let positive =
+            subject.intersection_with_fill(&clip, FillRule::Positive, FillRule::NonZero, 1.0);
+        assert_eq!(positive.0.len(), 1);
+        assert!(positive.0[0]
+            .exterior()
+            .0
+            .iter()
+            .all(|c| c.x <= 100.0 && c.y <= 100.0));
+
+        let negative =
+            subject.intersection_with_fill(&clip, FillRule::Negative, FillRule::NonZero, 1.0);
+        assert_eq!(negative.0.len(), 1);
+        assert!(negative.0[0]
+            .exterior()
+            .0
+            .iter()
+            .all(|c| c.x >= 50.0 && c.y >= 50.0));
+    }
 }